@@ -19,8 +19,14 @@ pub enum StorageError {
     #[error("Trip not found: {0}")]
     TripNotFound(String),
 
+    #[error("Conversation not found: {0}")]
+    ConversationNotFound(String),
+
     #[error("Invalid trip data: {0}")]
     InvalidTripData(String),
+
+    #[error("background task failed: {0}")]
+    Join(#[from] tokio::task::JoinError),
 }
 
 pub type StorageResult<T> = Result<T, StorageError>;
@@ -121,6 +127,14 @@ pub struct Activity {
     pub booking_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<String>,
+    /// Name of the `TransitProvider` backend that can refresh this
+    /// activity's live status (e.g. `"onboard"`, `"operator"`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    /// Train/service reference to pass to that provider, so the stored
+    /// activity can be re-queried for live status later.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_ref: Option<String>,
 }
 
 /// Day plan in the itinerary
@@ -156,7 +170,38 @@ pub struct Trip {
     pub duration: DateRange,
     pub preferences: UserPreferences,
     pub itinerary: Vec<DayPlan>,
-    pub status: String,
+    pub status: TripStatus,
+    #[serde(with = "serde_iso8601")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(with = "serde_iso8601")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    /// Schema version of this document on disk. Missing on legacy files,
+    /// which are treated as version 1 and migrated on load.
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
+}
+
+fn default_schema_version() -> u32 {
+    1
+}
+
+/// A single message in a planning-agent conversation
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Message {
+    pub role: String,
+    pub content: String,
+    #[serde(with = "serde_iso8601")]
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+/// A planning-agent chat conversation, optionally linked to the trip it
+/// produced
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Conversation {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trip_id: Option<String>,
+    pub messages: Vec<Message>,
     #[serde(with = "serde_iso8601")]
     pub created_at: chrono::DateTime<chrono::Utc>,
     #[serde(with = "serde_iso8601")]
@@ -189,6 +234,118 @@ mod serde_iso8601 {
     }
 }
 
+// ============================================================================
+// Trip Schema Migrations
+// ============================================================================
+
+/// The schema version newly-saved `Trip` documents are written at. Bumped
+/// whenever a `Migration` is added below.
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// A single step that brings a `Trip` document from one schema version to
+/// the next. Migrations run in ascending order against the raw JSON value,
+/// before it is ever deserialized into a `Trip`, so older documents that no
+/// longer match the current struct shape can still be read.
+pub trait Migration {
+    const FROM: u32;
+    const TO: u32;
+
+    fn migrate(&self, value: &mut serde_json::Value);
+}
+
+/// Object-safe view of a `Migration`, so migrations can be stored as
+/// `Box<dyn DynMigration>` in a registry (a trait with associated consts is
+/// not object-safe).
+trait DynMigration {
+    fn from_version(&self) -> u32;
+    fn to_version(&self) -> u32;
+    fn apply(&self, value: &mut serde_json::Value);
+}
+
+impl<M: Migration> DynMigration for M {
+    fn from_version(&self) -> u32 {
+        M::FROM
+    }
+    fn to_version(&self) -> u32 {
+        M::TO
+    }
+    fn apply(&self, value: &mut serde_json::Value) {
+        self.migrate(value)
+    }
+}
+
+/// v1 -> v2: `status` was a free-form string; it is now the `TripStatus`
+/// enum. Known legacy values map onto their matching variant; anything
+/// unrecognized falls back to `"draft"` rather than failing to load.
+struct StatusEnumMigration;
+
+impl Migration for StatusEnumMigration {
+    const FROM: u32 = 1;
+    const TO: u32 = 2;
+
+    fn migrate(&self, value: &mut serde_json::Value) {
+        let Some(obj) = value.as_object_mut() else {
+            return;
+        };
+        let normalized = obj
+            .get("status")
+            .and_then(|s| s.as_str())
+            .map(|s| s.to_lowercase())
+            .filter(|s| {
+                matches!(
+                    s.as_str(),
+                    "draft" | "planning" | "confirmed" | "completed" | "cancelled"
+                )
+            })
+            .unwrap_or_else(|| "draft".to_string());
+        obj.insert(
+            "status".to_string(),
+            serde_json::Value::String(normalized),
+        );
+    }
+}
+
+/// All migrations, in ascending `FROM` order.
+fn migrations() -> Vec<Box<dyn DynMigration>> {
+    vec![Box::new(StatusEnumMigration)]
+}
+
+/// Bring a raw trip document up to `CURRENT_SCHEMA_VERSION` by repeatedly
+/// looking up and applying whichever migration starts at the document's
+/// current version, until it reaches `CURRENT_SCHEMA_VERSION` or no
+/// migration covers the current version (a gap in the chain, which is left
+/// alone rather than guessed at). This does not assume `migrations()` is
+/// sorted or that each step's `TO` matches the next step's `FROM` in vec
+/// order — it re-searches from the current version every iteration, so
+/// out-of-order entries, multiple migrations with the same `FROM`, or a
+/// multi-step jump (v1 -> v3) all still chain correctly. Returns whether the
+/// document was changed, so the caller can decide to persist the upgrade.
+fn migrate_trip_value(value: &mut serde_json::Value) -> bool {
+    let mut version = value
+        .get("schema_version")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(1) as u32;
+    let original_version = version;
+
+    let steps = migrations();
+    while version < CURRENT_SCHEMA_VERSION {
+        let Some(migration) = steps.iter().find(|m| m.from_version() == version) else {
+            break;
+        };
+        migration.apply(value);
+        version = migration.to_version();
+    }
+
+    if let Some(obj) = value.as_object_mut() {
+        obj.insert(
+            "schema_version".to_string(),
+            serde_json::Value::Number(version.into()),
+        );
+    }
+
+    version != original_version
+}
+
 // ============================================================================
 // Storage Manager
 // ============================================================================
@@ -200,6 +357,58 @@ pub struct StorageManager {
     conversations_dir: PathBuf,
 }
 
+/// Environment variable that overrides the default cap on concurrently-
+/// loading trip/conversation files, for constrained environments that want
+/// to turn parallelism down (or up) without a code change.
+const MAX_PARALLEL_LOADS_ENV_VAR: &str = "TRIP_AGENT_MAX_PARALLEL_LOADS";
+
+/// Default cap on concurrently-loading trip files when one isn't supplied
+/// explicitly: `TRIP_AGENT_MAX_PARALLEL_LOADS` if set to a valid positive
+/// integer, otherwise the available parallelism.
+pub fn default_max_parallel_loads() -> usize {
+    std::env::var(MAX_PARALLEL_LOADS_ENV_VAR)
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|n| *n > 0)
+        .unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(4)
+        })
+}
+
+/// Monotonic counter mixed into temp file names so concurrent writers
+/// targeting the same path (a racing user edit and a migration resave, or
+/// two rapid saves) never collide on the same temp file.
+static TMP_FILE_COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// Write `value` to `path` atomically: serialize to a uniquely-named temp
+/// file in the same directory, then `rename` over the target. A crash or
+/// power loss mid-write leaves either the old file or the new one, never a
+/// truncated one; the unique name also keeps concurrent writers to the same
+/// path from stomping on each other's temp file before the rename.
+async fn write_json_atomic<T: serde::Serialize>(path: &std::path::Path, value: &T) -> StorageResult<()> {
+    let json = serde_json::to_string_pretty(value)?;
+    let unique = TMP_FILE_COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let tmp_path = path.with_extension(format!("tmp.{}.{}", std::process::id(), unique));
+    tokio::fs::write(&tmp_path, json).await?;
+    tokio::fs::rename(&tmp_path, path).await?;
+    Ok(())
+}
+
+/// Read a single trip file, running schema migrations and persisting the
+/// upgrade if the on-disk document was behind `CURRENT_SCHEMA_VERSION`.
+async fn read_trip_file(path: &std::path::Path) -> StorageResult<Trip> {
+    let content = tokio::fs::read_to_string(path).await?;
+    let mut value: serde_json::Value = serde_json::from_str(&content)?;
+    let migrated = migrate_trip_value(&mut value);
+    let trip: Trip = serde_json::from_value(value)?;
+    if migrated {
+        write_json_atomic(path, &trip).await?;
+    }
+    Ok(trip)
+}
+
 impl StorageManager {
     /// Create a new storage manager with the given data directory
     pub fn new(data_dir: PathBuf) -> StorageResult<Self> {
@@ -227,79 +436,92 @@ impl StorageManager {
         Self::new(data_dir)
     }
 
-    /// Save a trip to the file system
-    pub fn save_trip(&self, trip: &Trip) -> StorageResult<()> {
+    /// Save a trip to the file system, atomically, stamped with
+    /// `CURRENT_SCHEMA_VERSION`
+    pub async fn save_trip(&self, trip: &Trip) -> StorageResult<()> {
         let trip_path = self.trips_dir.join(format!("{}.json", trip.id));
-        let json = serde_json::to_string_pretty(trip)?;
-        std::fs::write(trip_path, json)?;
-        Ok(())
+        let mut trip = trip.clone();
+        trip.schema_version = CURRENT_SCHEMA_VERSION;
+        write_json_atomic(&trip_path, &trip).await
     }
 
-    /// Load all trips from the file system
-    pub fn load_trips(&self) -> StorageResult<Vec<Trip>> {
-        let mut trips = Vec::new();
-
-        let entries = std::fs::read_dir(&self.trips_dir)?;
-        for entry in entries {
-            let entry = entry?;
+    /// Load all trips from the file system, fanning out one task per file
+    /// with at most `max_parallel_loads` running concurrently.
+    pub async fn load_trips(&self, max_parallel_loads: usize) -> StorageResult<Vec<Trip>> {
+        let mut paths = Vec::new();
+        let mut entries = tokio::fs::read_dir(&self.trips_dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
             let path = entry.path();
-
             if path.extension().and_then(|s| s.to_str()) == Some("json") {
-                let content = std::fs::read_to_string(&path)?;
-                let trip: Trip = serde_json::from_str(&content)?;
-                trips.push(trip);
+                paths.push(path);
             }
         }
 
-        // Sort by updated_at descending
-        trips.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+        let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(max_parallel_loads.max(1)));
+        let mut join_set = tokio::task::JoinSet::new();
+        for path in paths {
+            let semaphore = semaphore.clone();
+            join_set.spawn(async move {
+                let _permit = semaphore
+                    .acquire_owned()
+                    .await
+                    .expect("semaphore is never closed");
+                read_trip_file(&path).await
+            });
+        }
+
+        let mut trips = Vec::new();
+        while let Some(result) = join_set.join_next().await {
+            trips.push(result??);
+        }
+
+        // Sort by updated_at descending; results arrive in JoinSet completion
+        // order (nondeterministic under concurrency), and trips saved in the
+        // same batch often share a timestamp, so break ties on id for a
+        // stable, reproducible order.
+        trips.sort_by(|a, b| (&b.updated_at, &b.id).cmp(&(&a.updated_at, &a.id)));
 
         Ok(trips)
     }
 
     /// Load a single trip by ID
-    pub fn load_trip(&self, id: &str) -> StorageResult<Trip> {
+    pub async fn load_trip(&self, id: &str) -> StorageResult<Trip> {
         let trip_path = self.trips_dir.join(format!("{}.json", id));
 
-        if !trip_path.exists() {
+        if !tokio::fs::try_exists(&trip_path).await? {
             return Err(StorageError::TripNotFound(id.to_string()));
         }
 
-        let content = std::fs::read_to_string(&trip_path)?;
-        let trip: Trip = serde_json::from_str(&content)?;
-
-        Ok(trip)
+        read_trip_file(&trip_path).await
     }
 
     /// Delete a trip by ID
-    pub fn delete_trip(&self, id: &str) -> StorageResult<()> {
+    pub async fn delete_trip(&self, id: &str) -> StorageResult<()> {
         let trip_path = self.trips_dir.join(format!("{}.json", id));
 
-        if !trip_path.exists() {
+        if !tokio::fs::try_exists(&trip_path).await? {
             return Err(StorageError::TripNotFound(id.to_string()));
         }
 
-        std::fs::remove_file(trip_path)?;
+        tokio::fs::remove_file(trip_path).await?;
         Ok(())
     }
 
-    /// Save user preferences
-    pub fn save_preferences(&self, prefs: &UserPreferences) -> StorageResult<()> {
+    /// Save user preferences, atomically
+    pub async fn save_preferences(&self, prefs: &UserPreferences) -> StorageResult<()> {
         let prefs_path = self.data_dir.join("preferences.json");
-        let json = serde_json::to_string_pretty(prefs)?;
-        std::fs::write(prefs_path, json)?;
-        Ok(())
+        write_json_atomic(&prefs_path, prefs).await
     }
 
     /// Load user preferences
-    pub fn load_preferences(&self) -> StorageResult<Option<UserPreferences>> {
+    pub async fn load_preferences(&self) -> StorageResult<Option<UserPreferences>> {
         let prefs_path = self.data_dir.join("preferences.json");
 
-        if !prefs_path.exists() {
+        if !tokio::fs::try_exists(&prefs_path).await? {
             return Ok(None);
         }
 
-        let content = std::fs::read_to_string(&prefs_path)?;
+        let content = tokio::fs::read_to_string(&prefs_path).await?;
         let prefs: UserPreferences = serde_json::from_str(&content)?;
 
         Ok(Some(prefs))
@@ -309,6 +531,80 @@ impl StorageManager {
     pub fn data_dir(&self) -> &PathBuf {
         &self.data_dir
     }
+
+    /// Save a conversation to the file system, atomically
+    pub async fn save_conversation(&self, conversation: &Conversation) -> StorageResult<()> {
+        let path = self
+            .conversations_dir
+            .join(format!("{}.json", conversation.id));
+        write_json_atomic(&path, conversation).await
+    }
+
+    /// Load a single conversation by ID
+    pub async fn load_conversation(&self, id: &str) -> StorageResult<Conversation> {
+        let path = self.conversations_dir.join(format!("{}.json", id));
+
+        if !tokio::fs::try_exists(&path).await? {
+            return Err(StorageError::ConversationNotFound(id.to_string()));
+        }
+
+        let content = tokio::fs::read_to_string(&path).await?;
+        let conversation: Conversation = serde_json::from_str(&content)?;
+        Ok(conversation)
+    }
+
+    /// Load all conversations from the file system, fanning out one task
+    /// per file with at most `max_parallel_loads` running concurrently.
+    pub async fn load_conversations(
+        &self,
+        max_parallel_loads: usize,
+    ) -> StorageResult<Vec<Conversation>> {
+        let mut paths = Vec::new();
+        let mut entries = tokio::fs::read_dir(&self.conversations_dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().and_then(|s| s.to_str()) == Some("json") {
+                paths.push(path);
+            }
+        }
+
+        let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(max_parallel_loads.max(1)));
+        let mut join_set = tokio::task::JoinSet::new();
+        for path in paths {
+            let semaphore = semaphore.clone();
+            join_set.spawn(async move {
+                let _permit = semaphore
+                    .acquire_owned()
+                    .await
+                    .expect("semaphore is never closed");
+                let content = tokio::fs::read_to_string(&path).await?;
+                serde_json::from_str::<Conversation>(&content).map_err(StorageError::from)
+            });
+        }
+
+        let mut conversations = Vec::new();
+        while let Some(result) = join_set.join_next().await {
+            conversations.push(result??);
+        }
+
+        // Sort by updated_at descending, breaking ties on id for the same
+        // reason as `load_trips`: JoinSet completion order is nondeterministic
+        // and batch-created conversations often share a timestamp.
+        conversations.sort_by(|a, b| (&b.updated_at, &b.id).cmp(&(&a.updated_at, &a.id)));
+        Ok(conversations)
+    }
+
+    /// Delete a conversation by ID
+    pub async fn delete_conversation(&self, id: &str) -> StorageResult<()> {
+        let path = self.conversations_dir.join(format!("{}.json", id));
+
+        if !tokio::fs::try_exists(&path).await? {
+            return Err(StorageError::ConversationNotFound(id.to_string()));
+        }
+
+        tokio::fs::remove_file(path).await?;
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -318,8 +614,53 @@ impl StorageManager {
 use tauri::State;
 use std::sync::Mutex;
 
-/// Global state for the storage manager
-pub struct StorageState(Mutex<StorageManager>);
+/// Global state for the storage manager, and anything started once at setup
+/// that needs to live for the app's lifetime (e.g. the file watcher).
+pub struct StorageState {
+    manager: Mutex<StorageManager>,
+    watcher: Mutex<Option<notify::RecommendedWatcher>>,
+    /// Cap on concurrently-loading trip files in `load_trips`. Configurable
+    /// so a constrained environment can turn it down; defaults to the
+    /// available parallelism.
+    max_parallel_loads: usize,
+}
+
+impl StorageState {
+    /// Create state with the default `max_parallel_loads`: the
+    /// `TRIP_AGENT_MAX_PARALLEL_LOADS` environment variable if set, else the
+    /// available parallelism. Use [`StorageState::with_max_parallel_loads`]
+    /// to set it explicitly instead.
+    pub fn new(manager: StorageManager) -> Self {
+        Self::with_max_parallel_loads(manager, default_max_parallel_loads())
+    }
+
+    /// Create state with an explicit cap on concurrently-loading files.
+    pub fn with_max_parallel_loads(manager: StorageManager, max_parallel_loads: usize) -> Self {
+        StorageState {
+            manager: Mutex::new(manager),
+            watcher: Mutex::new(None),
+            max_parallel_loads: max_parallel_loads.max(1),
+        }
+    }
+
+    pub fn max_parallel_loads(&self) -> usize {
+        self.max_parallel_loads
+    }
+
+    pub fn data_dir(&self) -> PathBuf {
+        self.manager.lock().unwrap().data_dir().clone()
+    }
+
+    /// Store the watcher handle so it keeps running for the app's lifetime;
+    /// dropping it tears the watch down, so this must be held somewhere.
+    pub fn set_watcher(&self, watcher: notify::RecommendedWatcher) {
+        *self.watcher.lock().unwrap() = Some(watcher);
+    }
+
+    pub fn has_watcher(&self) -> bool {
+        self.watcher.lock().unwrap().is_some()
+    }
+}
 
 /// Save a trip to local storage
 #[tauri::command]
@@ -328,17 +669,21 @@ pub async fn save_trip(
     trip: Trip,
 ) -> Result<(), String> {
     let manager = StorageManager::from_app_handle(&app)?;
-    manager.save_trip(&trip).map_err(String::from)?;
+    manager.save_trip(&trip).await.map_err(String::from)?;
     Ok(())
 }
 
-/// Load all trips from local storage
+/// Load all trips from local storage, in parallel
 #[tauri::command]
 pub async fn load_trips(
     app: tauri::AppHandle,
+    state: State<'_, StorageState>,
 ) -> Result<Vec<Trip>, String> {
     let manager = StorageManager::from_app_handle(&app)?;
-    manager.load_trips().map_err(String::from)
+    manager
+        .load_trips(state.max_parallel_loads())
+        .await
+        .map_err(String::from)
 }
 
 /// Load a single trip by ID
@@ -348,7 +693,7 @@ pub async fn load_trip(
     id: String,
 ) -> Result<Trip, String> {
     let manager = StorageManager::from_app_handle(&app)?;
-    manager.load_trip(&id).map_err(String::from)
+    manager.load_trip(&id).await.map_err(String::from)
 }
 
 /// Delete a trip by ID
@@ -358,7 +703,7 @@ pub async fn delete_trip(
     id: String,
 ) -> Result<(), String> {
     let manager = StorageManager::from_app_handle(&app)?;
-    manager.delete_trip(&id).map_err(String::from)?;
+    manager.delete_trip(&id).await.map_err(String::from)?;
     Ok(())
 }
 
@@ -369,7 +714,7 @@ pub async fn save_preferences(
     prefs: UserPreferences,
 ) -> Result<(), String> {
     let manager = StorageManager::from_app_handle(&app)?;
-    manager.save_preferences(&prefs).map_err(String::from)?;
+    manager.save_preferences(&prefs).await.map_err(String::from)?;
     Ok(())
 }
 
@@ -379,7 +724,7 @@ pub async fn load_preferences(
     app: tauri::AppHandle,
 ) -> Result<Option<UserPreferences>, String> {
     let manager = StorageManager::from_app_handle(&app)?;
-    manager.load_preferences().map_err(String::from)
+    manager.load_preferences().await.map_err(String::from)
 }
 
 /// Get the application data directory path (useful for debugging)
@@ -403,5 +748,240 @@ pub async fn trip_exists(
 ) -> Result<bool, String> {
     let manager = StorageManager::from_app_handle(&app)?;
     let trip_path = manager.trips_dir.join(format!("{}.json", id));
-    Ok(trip_path.exists())
+    Ok(tokio::fs::try_exists(trip_path).await.map_err(|e| e.to_string())?)
+}
+
+// ============================================================================
+// GTFS Transit Import
+// ============================================================================
+
+/// Import real transit connections from a GTFS feed into a trip's itinerary.
+///
+/// Looks up every scheduled trip between `origin_stop_id` and
+/// `destination_stop_id` that runs on `date` (format `YYYYMMDD`, matching
+/// GTFS's own date format), appends one `transit` activity per match to the
+/// `DayPlan` identified by `day_number`, and saves the updated trip.
+#[tauri::command]
+pub async fn import_gtfs_feed(
+    app: tauri::AppHandle,
+    trip_id: String,
+    day_number: i32,
+    feed_path: String,
+    origin_stop_id: String,
+    destination_stop_id: String,
+    date: String,
+) -> Result<Trip, String> {
+    let manager = StorageManager::from_app_handle(&app)?;
+    let mut trip = manager.load_trip(&trip_id).await?;
+
+    let date = chrono::NaiveDate::parse_from_str(&date, "%Y%m%d")
+        .map_err(|e| format!("invalid date '{date}': {e}"))?;
+
+    let feed = crate::transit::GtfsFeed::from_zip(std::path::Path::new(&feed_path))?;
+    let activities =
+        feed.find_transit_activities(&origin_stop_id, &destination_stop_id, date)?;
+
+    let day_plan = trip
+        .itinerary
+        .iter_mut()
+        .find(|d| d.day_number == day_number)
+        .ok_or_else(|| format!("day {day_number} not found in trip {trip_id}"))?;
+    day_plan.activities.extend(activities);
+
+    manager.save_trip(&trip).await.map_err(String::from)?;
+    Ok(trip)
+}
+
+// ============================================================================
+// Live Transit Status
+// ============================================================================
+
+/// Maximum number of attempts a live status query will make before giving up
+/// on a flaky gateway.
+const LIVE_STATUS_MAX_ATTEMPTS: u32 = 3;
+/// Delay between retries of a transient (502/503) live status failure.
+const LIVE_STATUS_RETRY_DELAY: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Refresh the live delay/platform/cancellation status of a stored transit
+/// activity, using whichever provider its `provider` field names.
+#[tauri::command]
+pub async fn refresh_activity_status(
+    app: tauri::AppHandle,
+    trip_id: String,
+    activity_id: String,
+) -> Result<crate::providers::LiveStatus, String> {
+    let manager = StorageManager::from_app_handle(&app)?;
+    let trip = manager.load_trip(&trip_id).await?;
+
+    let (day, activity) = trip
+        .itinerary
+        .iter()
+        .find_map(|day| {
+            day.activities
+                .iter()
+                .find(|a| a.id == activity_id)
+                .map(|a| (day, a))
+        })
+        .ok_or_else(|| format!("activity {activity_id} not found in trip {trip_id}"))?;
+
+    if activity.activity_type != "transit" {
+        return Err(format!("activity {activity_id} is not a transit activity"));
+    }
+    let provider_name = activity
+        .provider
+        .as_deref()
+        .ok_or_else(|| format!("activity {activity_id} has no provider configured"))?;
+    let train_ref = activity
+        .external_ref
+        .as_deref()
+        .ok_or_else(|| format!("activity {activity_id} has no external_ref configured"))?;
+    let scheduled_at = activity_scheduled_datetime(day, activity)?;
+
+    let provider = crate::providers::provider_for_name(provider_name)?;
+    let status = crate::providers::poll_with_retry(
+        LIVE_STATUS_MAX_ATTEMPTS,
+        LIVE_STATUS_RETRY_DELAY,
+        || provider.query(train_ref, scheduled_at),
+    )
+    .await?;
+
+    Ok(status)
+}
+
+/// Combine a `DayPlan`'s date with an activity's `time.start` (an `HH:MM:SS`
+/// string, possibly with hours >= 24 for after-midnight GTFS service) into
+/// the `DateTime<Utc>` the activity is actually scheduled at, so a live
+/// status lookup queries the right day's service rather than "now".
+fn activity_scheduled_datetime(
+    day: &DayPlan,
+    activity: &Activity,
+) -> Result<chrono::DateTime<chrono::Utc>, String> {
+    let parts: Vec<&str> = activity.time.start.split(':').collect();
+    let [hours, minutes, seconds] = parts.as_slice() else {
+        return Err(format!(
+            "invalid activity time '{}': expected HH:MM:SS",
+            activity.time.start
+        ));
+    };
+    let parse = |s: &str| {
+        s.parse::<i64>()
+            .map_err(|_| format!("invalid activity time '{}': expected HH:MM:SS", activity.time.start))
+    };
+    let offset_seconds = parse(hours)? * 3600 + parse(minutes)? * 60 + parse(seconds)?;
+    Ok(day.date + chrono::Duration::seconds(offset_seconds))
+}
+
+// ============================================================================
+// Filesystem Watcher
+// ============================================================================
+
+/// Start watching the trips directory and preferences file for out-of-band
+/// changes (a sync tool, another device, a manual edit), emitting a
+/// `trip-changed` event for each affected trip. Writes made by the app
+/// itself are reported too. Safe to call more than once; only the first
+/// call (or the start at setup) actually starts the watcher.
+#[tauri::command]
+pub async fn watch_trips(
+    app: tauri::AppHandle,
+    state: State<'_, StorageState>,
+) -> Result<(), String> {
+    if state.has_watcher() {
+        return Ok(());
+    }
+
+    let data_dir = state.data_dir();
+    let watcher = crate::watcher::start(app, data_dir).map_err(|e| e.to_string())?;
+    state.set_watcher(watcher);
+    Ok(())
+}
+
+// ============================================================================
+// Conversation Persistence
+// ============================================================================
+
+/// Save a conversation to local storage
+#[tauri::command]
+pub async fn save_conversation(
+    app: tauri::AppHandle,
+    conversation: Conversation,
+) -> Result<(), String> {
+    let manager = StorageManager::from_app_handle(&app)?;
+    manager
+        .save_conversation(&conversation)
+        .await
+        .map_err(String::from)?;
+    Ok(())
+}
+
+/// Load a single conversation by ID
+#[tauri::command]
+pub async fn load_conversation(
+    app: tauri::AppHandle,
+    id: String,
+) -> Result<Conversation, String> {
+    let manager = StorageManager::from_app_handle(&app)?;
+    manager.load_conversation(&id).await.map_err(String::from)
+}
+
+/// Load all conversations from local storage, in parallel
+#[tauri::command]
+pub async fn load_conversations(
+    app: tauri::AppHandle,
+    state: State<'_, StorageState>,
+) -> Result<Vec<Conversation>, String> {
+    let manager = StorageManager::from_app_handle(&app)?;
+    manager
+        .load_conversations(state.max_parallel_loads())
+        .await
+        .map_err(String::from)
+}
+
+/// Delete a conversation by ID
+#[tauri::command]
+pub async fn delete_conversation(
+    app: tauri::AppHandle,
+    id: String,
+) -> Result<(), String> {
+    let manager = StorageManager::from_app_handle(&app)?;
+    manager.delete_conversation(&id).await.map_err(String::from)?;
+    Ok(())
+}
+
+/// Append a message to a conversation, creating it first if it doesn't
+/// already exist, and re-save with an updated `updated_at`
+#[tauri::command]
+pub async fn append_message(
+    app: tauri::AppHandle,
+    conversation_id: String,
+    trip_id: Option<String>,
+    role: String,
+    content: String,
+) -> Result<Conversation, String> {
+    let manager = StorageManager::from_app_handle(&app)?;
+    let now = chrono::Utc::now();
+
+    let mut conversation = match manager.load_conversation(&conversation_id).await {
+        Ok(conversation) => conversation,
+        Err(StorageError::ConversationNotFound(_)) => Conversation {
+            id: conversation_id,
+            trip_id,
+            messages: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        },
+        Err(e) => return Err(e.into()),
+    };
+
+    conversation.messages.push(Message {
+        role,
+        content,
+        timestamp: now,
+    });
+    conversation.updated_at = now;
+
+    manager
+        .save_conversation(&conversation)
+        .await
+        .map_err(String::from)?;
+    Ok(conversation)
 }
@@ -2,6 +2,9 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod commands;
+mod providers;
+mod transit;
+mod watcher;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -14,6 +17,13 @@ pub fn run() {
             .build(),
         )?;
       }
+      let manager = commands::StorageManager::from_app_handle(app.handle())?;
+      let state = commands::StorageState::new(manager);
+      match watcher::start(app.handle().clone(), state.data_dir()) {
+        Ok(trip_watcher) => state.set_watcher(trip_watcher),
+        Err(e) => log::warn!("trip file watcher not started: {e}"),
+      }
+      app.manage(state);
       Ok(())
     })
     // Register storage commands
@@ -26,6 +36,14 @@ pub fn run() {
       commands::load_preferences,
       commands::get_data_dir,
       commands::trip_exists,
+      commands::import_gtfs_feed,
+      commands::refresh_activity_status,
+      commands::watch_trips,
+      commands::save_conversation,
+      commands::load_conversation,
+      commands::load_conversations,
+      commands::delete_conversation,
+      commands::append_message,
     ])
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
@@ -0,0 +1,250 @@
+// ============================================================================
+// Live Transit Status Providers
+// ============================================================================
+//
+// `TransitProvider` is a pluggable interface over whatever live data backend
+// a given operator exposes (an onboard portal, an operator timetable API,
+// ...). `refresh_activity_status` in `commands.rs` looks up the right
+// provider for a stored transit `Activity` by its `provider` field and
+// queries it for the current delay/platform/cancellation state.
+
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ProviderError {
+    #[error("HTTP request error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("unknown transit provider: {0}")]
+    UnknownProvider(String),
+
+    #[error("gave up after {0} attempts")]
+    RetriesExhausted(u32),
+}
+
+pub type ProviderResult<T> = Result<T, ProviderError>;
+
+impl From<ProviderError> for String {
+    fn from(error: ProviderError) -> Self {
+        error.to_string()
+    }
+}
+
+/// Live status of a single stop along a tracked journey.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct StopStatus {
+    pub stop_name: String,
+    pub scheduled: DateTime<Utc>,
+    pub estimated: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+}
+
+/// Live delay/platform/cancellation data for a tracked journey.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LiveStatus {
+    pub delay_minutes: i32,
+    pub scheduled: DateTime<Utc>,
+    pub estimated: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+    pub cancelled: bool,
+    pub stops: Vec<StopStatus>,
+}
+
+/// A backend that can answer "what is the live status of this train/bus
+/// right now?" for a given train/service reference.
+#[async_trait::async_trait]
+pub trait TransitProvider: Send + Sync {
+    async fn query(&self, train_ref: &str, date: DateTime<Utc>) -> ProviderResult<LiveStatus>;
+}
+
+fn is_transient_status(status: reqwest::StatusCode) -> bool {
+    status == reqwest::StatusCode::BAD_GATEWAY || status == reqwest::StatusCode::SERVICE_UNAVAILABLE
+}
+
+/// Call `f` up to `max_attempts` times in total with a fixed delay between
+/// attempts, retrying only when the failure looks transient (502/503 from
+/// the gateway).
+/// Any other error is returned immediately.
+pub async fn poll_with_retry<F, Fut>(
+    max_attempts: u32,
+    delay: std::time::Duration,
+    mut f: F,
+) -> ProviderResult<LiveStatus>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = ProviderResult<LiveStatus>>,
+{
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        match f().await {
+            Ok(status) => return Ok(status),
+            Err(ProviderError::Http(e)) if e.status().map(is_transient_status).unwrap_or(false) => {
+                if attempt >= max_attempts {
+                    return Err(ProviderError::RetriesExhausted(attempt));
+                }
+                tokio::time::sleep(delay).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OnboardStopPayload {
+    stop_name: String,
+    scheduled_time: DateTime<Utc>,
+    estimated_time: DateTime<Utc>,
+    platform: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OnboardStatusPayload {
+    delay_minutes: i32,
+    scheduled_departure: DateTime<Utc>,
+    estimated_departure: DateTime<Utc>,
+    platform: Option<String>,
+    cancelled: bool,
+    stops: Vec<OnboardStopPayload>,
+}
+
+/// Queries an onboard-portal-style API, as exposed by the train/bus's own
+/// wifi portal for passengers already travelling.
+pub struct OnboardPortalProvider {
+    base_url: String,
+    client: reqwest::Client,
+}
+
+impl OnboardPortalProvider {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        OnboardPortalProvider {
+            base_url: base_url.into(),
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TransitProvider for OnboardPortalProvider {
+    async fn query(&self, train_ref: &str, date: DateTime<Utc>) -> ProviderResult<LiveStatus> {
+        let url = format!("{}/trains/{}/status", self.base_url, train_ref);
+        let response = self
+            .client
+            .get(url)
+            .query(&[("date", date.to_rfc3339())])
+            .send()
+            .await?
+            .error_for_status()?;
+        let payload: OnboardStatusPayload = response.json().await?;
+
+        Ok(LiveStatus {
+            delay_minutes: payload.delay_minutes,
+            scheduled: payload.scheduled_departure,
+            estimated: payload.estimated_departure,
+            platform: payload.platform,
+            cancelled: payload.cancelled,
+            stops: payload
+                .stops
+                .into_iter()
+                .map(|s| StopStatus {
+                    stop_name: s.stop_name,
+                    scheduled: s.scheduled_time,
+                    estimated: s.estimated_time,
+                    platform: s.platform,
+                })
+                .collect(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OperatorStopPayload {
+    name: String,
+    scheduled_time: DateTime<Utc>,
+    expected_time: DateTime<Utc>,
+    platform: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OperatorTimetablePayload {
+    delay_minutes: i32,
+    scheduled_departure_time: DateTime<Utc>,
+    expected_departure_time: DateTime<Utc>,
+    platform: Option<String>,
+    is_cancelled: bool,
+    calling_points: Vec<OperatorStopPayload>,
+}
+
+/// Queries an operator's public timetable API, keyed by service id rather
+/// than a specific train the rider happens to be on.
+pub struct OperatorTimetableProvider {
+    base_url: String,
+    api_key: String,
+    client: reqwest::Client,
+}
+
+impl OperatorTimetableProvider {
+    pub fn new(base_url: impl Into<String>, api_key: impl Into<String>) -> Self {
+        OperatorTimetableProvider {
+            base_url: base_url.into(),
+            api_key: api_key.into(),
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TransitProvider for OperatorTimetableProvider {
+    async fn query(&self, train_ref: &str, date: DateTime<Utc>) -> ProviderResult<LiveStatus> {
+        let url = format!("{}/v1/services/{}", self.base_url, train_ref);
+        let response = self
+            .client
+            .get(url)
+            .bearer_auth(&self.api_key)
+            .query(&[("date", date.to_rfc3339())])
+            .send()
+            .await?
+            .error_for_status()?;
+        let payload: OperatorTimetablePayload = response.json().await?;
+
+        Ok(LiveStatus {
+            delay_minutes: payload.delay_minutes,
+            scheduled: payload.scheduled_departure_time,
+            estimated: payload.expected_departure_time,
+            platform: payload.platform,
+            cancelled: payload.is_cancelled,
+            stops: payload
+                .calling_points
+                .into_iter()
+                .map(|s| StopStatus {
+                    stop_name: s.name,
+                    scheduled: s.scheduled_time,
+                    estimated: s.expected_time,
+                    platform: s.platform,
+                })
+                .collect(),
+        })
+    }
+}
+
+/// Resolve the provider implementation named by an `Activity`'s `provider`
+/// field (e.g. `"onboard"`, `"operator"`).
+pub fn provider_for_name(name: &str) -> ProviderResult<Box<dyn TransitProvider>> {
+    match name {
+        "onboard" => Ok(Box::new(OnboardPortalProvider::new(
+            "https://onboard-portal.example.com/api",
+        ))),
+        "operator" => Ok(Box::new(OperatorTimetableProvider::new(
+            "https://operator-timetable.example.com/api",
+            std::env::var("OPERATOR_API_KEY").unwrap_or_default(),
+        ))),
+        other => Err(ProviderError::UnknownProvider(other.to_string())),
+    }
+}
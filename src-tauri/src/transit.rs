@@ -0,0 +1,345 @@
+// ============================================================================
+// GTFS Transit Import
+// ============================================================================
+//
+// Parses a standard GTFS (General Transit Feed Specification) zip archive and
+// turns scheduled trips between a stop pair into `Activity` entries that can
+// be dropped into a `DayPlan`.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::Path;
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::commands::{Activity, Coordinates, Location, TimeSlot};
+
+#[derive(Error, Debug)]
+pub enum TransitError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("zip archive error: {0}")]
+    Zip(#[from] zip::result::ZipError),
+
+    #[error("CSV parsing error: {0}")]
+    Csv(#[from] csv::Error),
+
+    #[error("GTFS feed is missing required file: {0}")]
+    MissingFile(&'static str),
+
+    #[error("malformed GTFS time value: {0}")]
+    InvalidTime(String),
+
+    #[error("malformed GTFS date value: {0}")]
+    InvalidDate(String),
+
+    #[error("unknown stop id: {0}")]
+    UnknownStop(String),
+
+    #[error("no scheduled trip found between {origin} and {destination} on the requested date")]
+    NoTripFound { origin: String, destination: String },
+}
+
+pub type TransitResult<T> = Result<T, TransitError>;
+
+impl From<TransitError> for String {
+    fn from(error: TransitError) -> Self {
+        error.to_string()
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct RawStop {
+    stop_id: String,
+    stop_name: String,
+    stop_lat: f64,
+    stop_lon: f64,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct RawRoute {
+    route_id: String,
+    route_short_name: String,
+    #[allow(dead_code)]
+    route_type: i32,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct RawTrip {
+    trip_id: String,
+    route_id: String,
+    service_id: String,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct RawStopTime {
+    trip_id: String,
+    stop_id: String,
+    arrival_time: String,
+    departure_time: String,
+    stop_sequence: i32,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct RawCalendar {
+    service_id: String,
+    monday: u8,
+    tuesday: u8,
+    wednesday: u8,
+    thursday: u8,
+    friday: u8,
+    saturday: u8,
+    sunday: u8,
+    start_date: String,
+    end_date: String,
+}
+
+/// A GTFS feed, parsed into lookup tables keyed by id.
+///
+/// `stop_times` is grouped and sorted by `stop_sequence` per trip so a trip's
+/// full stop sequence can be scanned for an origin/destination pair.
+pub struct GtfsFeed {
+    stops: HashMap<String, RawStop>,
+    routes: HashMap<String, RawRoute>,
+    trips: HashMap<String, RawTrip>,
+    stop_times_by_trip: HashMap<String, Vec<RawStopTime>>,
+    calendar: HashMap<String, RawCalendar>,
+}
+
+/// Parse raw seconds-since-midnight from a GTFS `HH:MM:SS` time value.
+///
+/// GTFS deliberately allows hours >= 24 to represent service that continues
+/// past midnight on the same "service day", so this does not use
+/// `chrono::NaiveTime`, which would reject e.g. `25:10:00`.
+fn parse_gtfs_time(value: &str) -> TransitResult<i64> {
+    let parts: Vec<&str> = value.trim().split(':').collect();
+    if parts.len() != 3 {
+        return Err(TransitError::InvalidTime(value.to_string()));
+    }
+    let hours: i64 = parts[0]
+        .parse()
+        .map_err(|_| TransitError::InvalidTime(value.to_string()))?;
+    let minutes: i64 = parts[1]
+        .parse()
+        .map_err(|_| TransitError::InvalidTime(value.to_string()))?;
+    let seconds: i64 = parts[2]
+        .parse()
+        .map_err(|_| TransitError::InvalidTime(value.to_string()))?;
+    Ok(hours * 3600 + minutes * 60 + seconds)
+}
+
+/// Format raw seconds-since-midnight back into a `HH:MM:SS` string, preserving
+/// hours >= 24 for after-midnight service.
+fn format_gtfs_time(total_seconds: i64) -> String {
+    let hours = total_seconds / 3600;
+    let minutes = (total_seconds % 3600) / 60;
+    let seconds = total_seconds % 60;
+    format!("{hours:02}:{minutes:02}:{seconds:02}")
+}
+
+fn parse_gtfs_date(value: &str) -> TransitResult<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y%m%d")
+        .map_err(|_| TransitError::InvalidDate(value.to_string()))
+}
+
+fn read_csv_entry<T: serde::de::DeserializeOwned>(
+    archive: &mut zip::ZipArchive<std::fs::File>,
+    name: &'static str,
+) -> TransitResult<Vec<T>> {
+    let mut file = archive
+        .by_name(name)
+        .map_err(|_| TransitError::MissingFile(name))?;
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)?;
+
+    let mut reader = csv::Reader::from_reader(contents.as_bytes());
+    let mut records = Vec::new();
+    for result in reader.deserialize() {
+        records.push(result?);
+    }
+    Ok(records)
+}
+
+impl GtfsFeed {
+    /// Parse a GTFS zip archive from disk into lookup tables keyed by id.
+    pub fn from_zip(path: &Path) -> TransitResult<Self> {
+        let file = std::fs::File::open(path)?;
+        let mut archive = zip::ZipArchive::new(file)?;
+
+        let stops: HashMap<String, RawStop> = read_csv_entry(&mut archive, "stops.txt")?
+            .into_iter()
+            .map(|s: RawStop| (s.stop_id.clone(), s))
+            .collect();
+
+        let routes: HashMap<String, RawRoute> = read_csv_entry(&mut archive, "routes.txt")?
+            .into_iter()
+            .map(|r: RawRoute| (r.route_id.clone(), r))
+            .collect();
+
+        let trips: HashMap<String, RawTrip> = read_csv_entry(&mut archive, "trips.txt")?
+            .into_iter()
+            .map(|t: RawTrip| (t.trip_id.clone(), t))
+            .collect();
+
+        let mut stop_times_by_trip: HashMap<String, Vec<RawStopTime>> = HashMap::new();
+        for stop_time in read_csv_entry::<RawStopTime>(&mut archive, "stop_times.txt")? {
+            stop_times_by_trip
+                .entry(stop_time.trip_id.clone())
+                .or_default()
+                .push(stop_time);
+        }
+        for stop_times in stop_times_by_trip.values_mut() {
+            stop_times.sort_by_key(|st| st.stop_sequence);
+        }
+
+        let calendar: HashMap<String, RawCalendar> = read_csv_entry(&mut archive, "calendar.txt")?
+            .into_iter()
+            .map(|c: RawCalendar| (c.service_id.clone(), c))
+            .collect();
+
+        Ok(GtfsFeed {
+            stops,
+            routes,
+            trips,
+            stop_times_by_trip,
+            calendar,
+        })
+    }
+
+    /// Whether `service_id` runs on `date`, per `calendar.txt`'s weekday flags
+    /// and date range.
+    fn service_active_on(&self, service_id: &str, date: NaiveDate) -> TransitResult<bool> {
+        let Some(entry) = self.calendar.get(service_id) else {
+            return Ok(false);
+        };
+        let start = parse_gtfs_date(&entry.start_date)?;
+        let end = parse_gtfs_date(&entry.end_date)?;
+        if date < start || date > end {
+            return Ok(false);
+        }
+
+        use chrono::Datelike;
+        let runs_today = match date.weekday() {
+            chrono::Weekday::Mon => entry.monday,
+            chrono::Weekday::Tue => entry.tuesday,
+            chrono::Weekday::Wed => entry.wednesday,
+            chrono::Weekday::Thu => entry.thursday,
+            chrono::Weekday::Fri => entry.friday,
+            chrono::Weekday::Sat => entry.saturday,
+            chrono::Weekday::Sun => entry.sunday,
+        };
+        Ok(runs_today == 1)
+    }
+
+    /// Find every trip that visits `origin_stop_id` before
+    /// `destination_stop_id` (by `stop_sequence`) on a service active on
+    /// `date`, and materialize each as a transit `Activity`.
+    pub fn find_transit_activities(
+        &self,
+        origin_stop_id: &str,
+        destination_stop_id: &str,
+        date: NaiveDate,
+    ) -> TransitResult<Vec<Activity>> {
+        if !self.stops.contains_key(origin_stop_id) {
+            return Err(TransitError::UnknownStop(origin_stop_id.to_string()));
+        }
+        if !self.stops.contains_key(destination_stop_id) {
+            return Err(TransitError::UnknownStop(destination_stop_id.to_string()));
+        }
+
+        let mut activities = Vec::new();
+        for trip in self.trips.values() {
+            if !self.service_active_on(&trip.service_id, date)? {
+                continue;
+            }
+
+            let Some(stop_times) = self.stop_times_by_trip.get(&trip.trip_id) else {
+                continue;
+            };
+            let origin_stop_time = stop_times.iter().find(|st| st.stop_id == origin_stop_id);
+            let destination_stop_time = stop_times
+                .iter()
+                .find(|st| st.stop_id == destination_stop_id);
+
+            let (Some(origin_st), Some(destination_st)) =
+                (origin_stop_time, destination_stop_time)
+            else {
+                continue;
+            };
+            if origin_st.stop_sequence >= destination_st.stop_sequence {
+                continue;
+            }
+
+            activities.push(self.build_activity(trip, origin_st, destination_st)?);
+        }
+
+        if activities.is_empty() {
+            return Err(TransitError::NoTripFound {
+                origin: origin_stop_id.to_string(),
+                destination: destination_stop_id.to_string(),
+            });
+        }
+
+        // `self.trips` is a HashMap, so iteration order (and thus the order
+        // activities were pushed) is randomized per-process; break ties on
+        // `id` so the same feed/query/date always produces the same order.
+        activities.sort_by_key(|a| (a.time.start.clone(), a.id.clone()));
+        Ok(activities)
+    }
+
+    fn build_activity(
+        &self,
+        trip: &RawTrip,
+        origin_st: &RawStopTime,
+        destination_st: &RawStopTime,
+    ) -> TransitResult<Activity> {
+        let origin_stop = self
+            .stops
+            .get(&origin_st.stop_id)
+            .ok_or_else(|| TransitError::UnknownStop(origin_st.stop_id.clone()))?;
+        let destination_stop = self
+            .stops
+            .get(&destination_st.stop_id)
+            .ok_or_else(|| TransitError::UnknownStop(destination_st.stop_id.clone()))?;
+        let route = self.routes.get(&trip.route_id);
+
+        let departure_seconds = parse_gtfs_time(&origin_st.departure_time)?;
+        let arrival_seconds = parse_gtfs_time(&destination_st.arrival_time)?;
+        let duration_minutes = ((arrival_seconds - departure_seconds) / 60) as i32;
+
+        let route_name = route
+            .map(|r| r.route_short_name.clone())
+            .unwrap_or_else(|| trip.route_id.clone());
+
+        Ok(Activity {
+            id: format!("transit-{}", trip.trip_id),
+            activity_type: "transit".to_string(),
+            name: format!("{} to {}", route_name, destination_stop.stop_name),
+            description: Some(format!(
+                "Transit from {} to {}",
+                origin_stop.stop_name, destination_stop.stop_name
+            )),
+            location: Location {
+                name: origin_stop.stop_name.clone(),
+                address: origin_stop.stop_name.clone(),
+                coordinates: Some(Coordinates {
+                    lat: origin_stop.stop_lat,
+                    lng: origin_stop.stop_lon,
+                }),
+            },
+            time: TimeSlot {
+                start: format_gtfs_time(departure_seconds),
+                end: format_gtfs_time(arrival_seconds),
+                duration: duration_minutes,
+            },
+            cost: None,
+            rating: None,
+            booking_url: None,
+            notes: None,
+            provider: None,
+            external_ref: None,
+        })
+    }
+}
@@ -0,0 +1,168 @@
+// ============================================================================
+// Trip File Watcher
+// ============================================================================
+//
+// Watches `trips_dir` and `preferences.json` for changes made outside the
+// app itself (a sync tool, another device, a manual edit) and emits a
+// `trip-changed` event for the frontend. Naive filesystem watchers fire
+// several create/modify events for a single logical write, so events are
+// debounced per path and coalesced into one notification. Events are not
+// filtered by origin: the app's own writes are reported as well.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use tauri::{AppHandle, Emitter};
+
+use crate::commands::{StorageManager, Trip, UserPreferences};
+
+/// Events within this window of each other, for the same path, are
+/// coalesced into a single emitted change.
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(200);
+
+/// Payload emitted on the `trip-changed` event.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TripChangeEvent {
+    pub kind: TripChangeKind,
+    pub trip_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trip: Option<Trip>,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TripChangeKind {
+    Updated,
+    Deleted,
+}
+
+/// Payload emitted on the `preferences-changed` event.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreferencesChangeEvent {
+    pub kind: TripChangeKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferences: Option<UserPreferences>,
+}
+
+/// Start watching `data_dir`'s `trips/` directory and `preferences.json`.
+/// The returned watcher must be kept alive (e.g. in `StorageState`) for the
+/// duration of the watch; dropping it stops delivery.
+pub fn start(app: AppHandle, data_dir: PathBuf) -> notify::Result<RecommendedWatcher> {
+    let trips_dir = data_dir.join("trips");
+    let preferences_path = data_dir.join("preferences.json");
+
+    let (tx, rx) = channel::<notify::Event>();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        if let Ok(event) = res {
+            let _ = tx.send(event);
+        }
+    })?;
+    watcher.watch(&trips_dir, RecursiveMode::NonRecursive)?;
+    // Watch `data_dir` itself, not just `preferences_path`, so the very
+    // first write of `preferences.json` (e.g. a sync tool pushing it down
+    // for the first time) is seen too, instead of only changes to an
+    // already-existing file.
+    watcher.watch(&data_dir, RecursiveMode::NonRecursive)?;
+
+    std::thread::spawn(move || debounce_loop(app, data_dir, trips_dir, preferences_path, rx));
+
+    Ok(watcher)
+}
+
+fn debounce_loop(
+    app: AppHandle,
+    data_dir: PathBuf,
+    trips_dir: PathBuf,
+    preferences_path: PathBuf,
+    rx: std::sync::mpsc::Receiver<notify::Event>,
+) {
+    let mut pending: HashMap<PathBuf, Instant> = HashMap::new();
+
+    loop {
+        match rx.recv_timeout(Duration::from_millis(50)) {
+            Ok(event) => {
+                for path in event.paths {
+                    pending.insert(path, Instant::now());
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+
+        let ready: Vec<PathBuf> = pending
+            .iter()
+            .filter(|(_, seen)| seen.elapsed() >= DEBOUNCE_WINDOW)
+            .map(|(path, _)| path.clone())
+            .collect();
+
+        for path in ready {
+            pending.remove(&path);
+            if path == preferences_path {
+                handle_preferences_change(&app, &data_dir, &path);
+            } else {
+                handle_trip_change(&app, &data_dir, &trips_dir, &path);
+            }
+        }
+    }
+}
+
+fn handle_trip_change(app: &AppHandle, data_dir: &Path, trips_dir: &Path, path: &Path) {
+    if path.extension().and_then(|s| s.to_str()) != Some("json") || path.parent() != Some(trips_dir)
+    {
+        return;
+    }
+    let Some(trip_id) = path.file_stem().and_then(|s| s.to_str()) else {
+        return;
+    };
+
+    let Ok(manager) = StorageManager::new(data_dir.to_path_buf()) else {
+        return;
+    };
+
+    let event = if path.exists() {
+        match tauri::async_runtime::block_on(manager.load_trip(trip_id)) {
+            Ok(trip) => TripChangeEvent {
+                kind: TripChangeKind::Updated,
+                trip_id: trip_id.to_string(),
+                trip: Some(trip),
+            },
+            Err(_) => return,
+        }
+    } else {
+        TripChangeEvent {
+            kind: TripChangeKind::Deleted,
+            trip_id: trip_id.to_string(),
+            trip: None,
+        }
+    };
+
+    let _ = app.emit("trip-changed", event);
+}
+
+fn handle_preferences_change(app: &AppHandle, data_dir: &Path, path: &Path) {
+    let Ok(manager) = StorageManager::new(data_dir.to_path_buf()) else {
+        return;
+    };
+
+    let event = if path.exists() {
+        match tauri::async_runtime::block_on(manager.load_preferences()) {
+            Ok(preferences) => PreferencesChangeEvent {
+                kind: TripChangeKind::Updated,
+                preferences,
+            },
+            Err(_) => return,
+        }
+    } else {
+        PreferencesChangeEvent {
+            kind: TripChangeKind::Deleted,
+            preferences: None,
+        }
+    };
+
+    let _ = app.emit("preferences-changed", event);
+}